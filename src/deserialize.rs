@@ -1,79 +1,30 @@
+use std::collections::HashMap;
 use std::convert::{TryFrom, TryInto};
 use std::fmt::Formatter;
 use std::io::Read;
 use std::marker::PhantomData;
 
-use crate::Result;
+use crate::{Error, Result};
 
-use serde::de::{Deserialize, Deserializer, Error, IntoDeserializer};
+use serde::de::{Deserialize, DeserializeOwned, Error as DeError, IntoDeserializer};
+use serde_json::value::RawValue;
+#[cfg(feature = "wkt")]
+use wkt::TryFromWkt;
 
 use crate::JsonValue;
 
-pub struct FeatureCollectionVisitor;
-
-impl FeatureCollectionVisitor {
-    fn new() -> Self {
-        Self
-    }
-}
-
-impl<'de> serde::de::Visitor<'de> for FeatureCollectionVisitor {
-    type Value = Vec<JsonValue>;
-
-    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-        write!(formatter, "a valid GeoJSON Feature object")
-    }
-
-    fn visit_map<A>(self, mut map_access: A) -> std::result::Result<Self::Value, A::Error>
-    where
-        A: serde::de::MapAccess<'de>,
-    {
-        let mut has_feature_collection_type = false;
-        let mut features = None;
-        while let Some((key, value)) = map_access.next_entry::<String, JsonValue>()? {
-            if key == "type" {
-                if value == JsonValue::String("FeatureCollection".to_string()) {
-                    has_feature_collection_type = true;
-                } else {
-                    return Err(A::Error::custom("invalid type for feature collection"));
-                }
-            } else if key == "features" {
-                if let JsonValue::Array(value) = value {
-                    if features.is_some() {
-                        return Err(A::Error::custom(
-                            "Encountered more than one list of `features`",
-                        ));
-                    }
-                    features = Some(value);
-                } else {
-                    return Err(A::Error::custom("`features` had unexpected value"));
-                }
-            } else {
-                return Err(A::Error::custom(
-                    "foreign members are not handled by FeatureCollection deserializer",
-                ));
-            }
-        }
-
-        if let Some(features) = features {
-            if has_feature_collection_type {
-                Ok(features)
-            } else {
-                Err(A::Error::custom("No `type` field was found"))
-            }
-        } else {
-            Err(A::Error::custom("No `features` field was found"))
-        }
-    }
-}
-
 struct FeatureVisitor<D> {
+    /// When `false`, members other than `type`/`geometry`/`properties` are
+    /// flattened onto the target alongside `properties` instead of being
+    /// rejected (see [`FeatureReader::strict`](crate::FeatureReader::strict)).
+    strict: bool,
     _marker: PhantomData<D>,
 }
 
 impl<D> FeatureVisitor<D> {
-    fn new() -> Self {
+    fn new(strict: bool) -> Self {
         Self {
+            strict,
             _marker: PhantomData,
         }
     }
@@ -94,7 +45,6 @@ where
         A: serde::de::MapAccess<'de>,
     {
         let mut has_feature_type = false;
-        use std::collections::HashMap;
         let mut hash_map: HashMap<String, crate::JsonValue> = HashMap::new();
 
         while let Some((key, value)) = map_access.next_entry::<String, JsonValue>()? {
@@ -107,13 +57,11 @@ where
                     ));
                 }
             } else if key == "geometry" {
-                if let JsonValue::Object(_) = value {
-                    hash_map.insert("geometry".to_string(), value);
-                } else {
-                    return Err(A::Error::custom(
-                        "GeoJSON Feature had a unexpected geometry",
-                    ));
-                }
+                // Usually a GeoJSON geometry object, but a target field using
+                // `deserialize_geometry_from_wkt` expects a WKT string
+                // instead -- let the target's own field deserializer decide
+                // whether the shape is acceptable rather than assuming here.
+                hash_map.insert("geometry".to_string(), value);
             } else if key == "properties" {
                 if let JsonValue::Object(properties) = value {
                     // flatten properties onto struct
@@ -125,48 +73,499 @@ where
                         "GeoJSON Feature had a unexpected geometry",
                     ));
                 }
-            } else {
+            } else if self.strict {
                 return Err(A::Error::custom(
                     "foreign members are not handled by FeatureCollection deserializer",
                 ));
+            } else {
+                // GeoJSON explicitly permits foreign members (`id`, `bbox`, vendor
+                // extensions, ...); flatten them in alongside `properties` so a
+                // target with a matching field (or a `#[serde(flatten)]` catch-all)
+                // can pick them up.
+                hash_map.insert(key, value);
             }
         }
 
         if has_feature_type {
-            // What do I actually do here? serde-transcode? or create a new MapAccess or Struct that
-            // has the fields needed by a child visitor - perhaps using serde::de::value::MapAccessDeserializer?
-            // use serde::de::value::MapAccessDeserializer;
             let d2 = hash_map.into_deserializer();
-            Ok(serde::Deserialize::deserialize(d2).expect("TODO"))
+            serde::Deserialize::deserialize(d2).map_err(A::Error::custom)
         } else {
-            return Err(A::Error::custom(
+            Err(A::Error::custom(
                 "A GeoJSON Feature must have a `type: \"Feature\"` field, but found none.",
-            ));
+            ))
         }
     }
 }
 
-pub(crate) fn deserialize_collection_features_from_reader<'de, D>(
-    feature_collection_reader: impl Read,
-) -> Result<impl Iterator<Item = Result<D>>>
-where
-    D: Deserialize<'de>,
-{
-    let mut deserializer = serde_json::Deserializer::from_reader(feature_collection_reader);
+/// Turns a [`std::io::Error`] or a message into this crate's [`Error`] type,
+/// going through `serde_json::Error` (which already has a `From` impl here)
+/// since it implements `serde::de::Error::custom` without needing a live
+/// `Deserializer` to call it on.
+fn scan_error<T: std::fmt::Display>(msg: T) -> Error {
+    <serde_json::Error as DeError>::custom(msg).into()
+}
 
-    // TODO: rather than deserializing the entirety of the `features:` array into memory here, it'd
-    // be nice to stream the features. However, I ran into difficulty while trying to return any
-    // borrowed reference from the visitor methods (e.g. MapAccess)
-    let visitor = FeatureCollectionVisitor::new();
-    let objects = deserializer.deserialize_map(visitor)?;
+/// Size of [`ByteCursor`]'s internal read buffer. Chosen to cut the number of
+/// `Read::read` calls (and thus syscalls, for unbuffered readers) down from
+/// one per byte to one per 8 KiB on large documents.
+const BYTE_CURSOR_BUFFER_SIZE: usize = 8 * 1024;
 
-    Ok(objects.into_iter().map(|feature_value| {
-        let deserializer = feature_value.into_deserializer();
-        let visitor = FeatureVisitor::new();
-        let record: D = deserializer.deserialize_map(visitor)?;
+/// A single-byte-lookahead cursor over a [`Read`]er, used to find the raw
+/// byte span of one JSON value at a time so that [`FeatureCollectionReader`]
+/// never has to hold more than one `Feature`'s worth of JSON in memory.
+///
+/// Bytes are served out of an internal buffer refilled in bulk, rather than
+/// with one `Read::read` call per byte, since a multi-hundred-MB document is
+/// exactly the case this streaming reader is meant for.
+struct ByteCursor<R> {
+    reader: R,
+    buf: Box<[u8]>,
+    pos: usize,
+    filled: usize,
+}
+
+impl<R: Read> ByteCursor<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buf: vec![0u8; BYTE_CURSOR_BUFFER_SIZE].into_boxed_slice(),
+            pos: 0,
+            filled: 0,
+        }
+    }
+
+    /// Refills the internal buffer, returning `false` at end of input.
+    fn fill_buffer(&mut self) -> Result<bool> {
+        loop {
+            return match self.reader.read(&mut self.buf) {
+                Ok(0) => Ok(false),
+                Ok(n) => {
+                    self.pos = 0;
+                    self.filled = n;
+                    Ok(true)
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => Err(scan_error(e)),
+            };
+        }
+    }
+
+    fn read_byte(&mut self) -> Result<Option<u8>> {
+        if self.pos >= self.filled && !self.fill_buffer()? {
+            return Ok(None);
+        }
+        let b = self.buf[self.pos];
+        self.pos += 1;
+        Ok(Some(b))
+    }
+
+    fn peek_byte(&mut self) -> Result<Option<u8>> {
+        if self.pos >= self.filled && !self.fill_buffer()? {
+            return Ok(None);
+        }
+        Ok(Some(self.buf[self.pos]))
+    }
+
+    fn skip_whitespace(&mut self) -> Result<()> {
+        while let Some(b) = self.peek_byte()? {
+            if b.is_ascii_whitespace() {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        Ok(())
+    }
 
-        Ok(record)
-    }))
+    fn expect_byte(&mut self, expected: u8) -> Result<()> {
+        match self.read_byte()? {
+            Some(b) if b == expected => Ok(()),
+            Some(b) => Err(scan_error(format!(
+                "expected '{}' but found '{}'",
+                expected as char, b as char
+            ))),
+            None => Err(scan_error(format!(
+                "expected '{}' but reached end of input",
+                expected as char
+            ))),
+        }
+    }
+
+    /// Consumes one JSON string literal (including the surrounding quotes),
+    /// appending its raw bytes to `sink`.
+    fn consume_string(&mut self, sink: &mut Vec<u8>) -> Result<()> {
+        self.expect_byte(b'"')?;
+        sink.push(b'"');
+        loop {
+            let b = self
+                .read_byte()?
+                .ok_or_else(|| scan_error("unexpected end of input in a JSON string"))?;
+            sink.push(b);
+            match b {
+                b'\\' => {
+                    let escaped = self
+                        .read_byte()?
+                        .ok_or_else(|| scan_error("unexpected end of input in a JSON string"))?;
+                    sink.push(escaped);
+                    if escaped == b'u' {
+                        for _ in 0..4 {
+                            let hex = self.read_byte()?.ok_or_else(|| {
+                                scan_error("unexpected end of input in a \\u escape")
+                            })?;
+                            sink.push(hex);
+                        }
+                    }
+                }
+                b'"' => break,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Consumes one JSON value -- a string, number, object, array, or
+    /// literal -- appending its raw bytes to `sink` and leaving the cursor
+    /// positioned right after it. Nesting is tracked as a single combined
+    /// depth counter since we only need to find the value's end, not
+    /// validate its grammar.
+    fn consume_value(&mut self, sink: &mut Vec<u8>) -> Result<()> {
+        self.skip_whitespace()?;
+        match self
+            .peek_byte()?
+            .ok_or_else(|| scan_error("unexpected end of input in a JSON value"))?
+        {
+            b'"' => self.consume_string(sink),
+            b'{' | b'[' => {
+                let mut depth: i32 = 0;
+                loop {
+                    let b = self
+                        .read_byte()?
+                        .ok_or_else(|| scan_error("unexpected end of input in a JSON value"))?;
+                    match b {
+                        b'"' => {
+                            sink.push(b);
+                            // the opening quote is already in `sink`; consume the rest of
+                            // the string onto the same buffer.
+                            loop {
+                                let sb = self.read_byte()?.ok_or_else(|| {
+                                    scan_error("unexpected end of input in a JSON string")
+                                })?;
+                                sink.push(sb);
+                                match sb {
+                                    b'\\' => {
+                                        let escaped = self.read_byte()?.ok_or_else(|| {
+                                            scan_error("unexpected end of input in a JSON string")
+                                        })?;
+                                        sink.push(escaped);
+                                        if escaped == b'u' {
+                                            for _ in 0..4 {
+                                                let hex = self.read_byte()?.ok_or_else(|| {
+                                                    scan_error(
+                                                        "unexpected end of input in a \\u escape",
+                                                    )
+                                                })?;
+                                                sink.push(hex);
+                                            }
+                                        }
+                                    }
+                                    b'"' => break,
+                                    _ => {}
+                                }
+                            }
+                        }
+                        b'{' | b'[' => {
+                            sink.push(b);
+                            depth += 1;
+                        }
+                        b'}' | b']' => {
+                            sink.push(b);
+                            depth -= 1;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        _ => sink.push(b),
+                    }
+                }
+                Ok(())
+            }
+            _ => {
+                // a number, `true`, `false`, or `null`: consume until the next byte
+                // that can't be part of one (whitespace or a structural delimiter).
+                loop {
+                    match self.peek_byte()? {
+                        Some(b)
+                            if !b.is_ascii_whitespace()
+                                && !matches!(b, b',' | b']' | b'}' | b':') =>
+                        {
+                            sink.push(self.read_byte()?.expect("just peeked"));
+                        }
+                        _ => break,
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn read_json_string(&mut self) -> Result<String> {
+        let mut buf = Vec::new();
+        self.consume_string(&mut buf)?;
+        serde_json::from_slice(&buf).map_err(Into::into)
+    }
+}
+
+/// Parses one already-extracted `Feature`'s raw JSON bytes into `D`, giving
+/// [`FeatureVisitor`] a [`serde::de::Deserializer`] to drive.
+///
+/// This is the seam that lets [`FeatureCollectionReader`] swap in a faster
+/// JSON parser for converting each already-isolated `Feature`'s bytes into
+/// `D`. `bytes` is `&mut [u8]` rather than `&[u8]` because that's what
+/// in-place parsers like `simd-json` require.
+///
+/// Note what this does *not* cover: finding where each `Feature` starts and
+/// ends within the surrounding `FeatureCollection` is still done by the
+/// hand-rolled, backend-agnostic byte scanner added for streaming (see
+/// [`ByteCursor`]), one byte at a time, regardless of which `JsonBackend` is
+/// selected. Swapping in [`SimdJsonBackend`] speeds up parsing features with
+/// many/large/deeply-nested fields, but it does not speed up scanning a
+/// FeatureCollection with many small features, since that cost is paid
+/// entirely outside this trait.
+pub trait JsonBackend {
+    fn deserialize_feature<D: DeserializeOwned>(bytes: &mut [u8], strict: bool) -> Result<D>;
+}
+
+/// The default backend, using [`serde_json`].
+pub struct SerdeJsonBackend;
+
+impl JsonBackend for SerdeJsonBackend {
+    fn deserialize_feature<D: DeserializeOwned>(bytes: &mut [u8], strict: bool) -> Result<D> {
+        let mut deserializer = serde_json::Deserializer::from_slice(bytes);
+        deserializer
+            .deserialize_map(FeatureVisitor::<D>::new(strict))
+            .map_err(Into::into)
+    }
+}
+
+/// A SIMD-accelerated backend, using [`simd_json`]. Opt in with the
+/// `simd-json` cargo feature; pair it with
+/// [`FeatureReader::from_bytes`](crate::FeatureReader::from_bytes) and
+/// [`FeatureReader::deserialize_with_backend`](crate::FeatureReader::deserialize_with_backend)
+/// when you already hold the whole document in memory.
+///
+/// See [`JsonBackend`] for what this does (and doesn't) speed up.
+#[cfg(feature = "simd-json")]
+pub struct SimdJsonBackend;
+
+#[cfg(feature = "simd-json")]
+impl JsonBackend for SimdJsonBackend {
+    fn deserialize_feature<D: DeserializeOwned>(bytes: &mut [u8], strict: bool) -> Result<D> {
+        let mut deserializer = simd_json::Deserializer::from_slice(bytes)
+            .map_err(|err| scan_error(format!("invalid JSON for simd-json backend: {}", err)))?;
+        deserializer
+            .deserialize_map(FeatureVisitor::<D>::new(strict))
+            .map_err(|err| scan_error(format!("simd-json deserialize error: {}", err)))
+    }
+}
+
+enum ScanState {
+    /// Scanning top-level members, looking for `features`.
+    Header,
+    /// Positioned inside the `features` array, yielding one element at a time.
+    Features,
+    /// The `features` array has been consumed; scanning any remaining
+    /// top-level members (and confirming `type` was seen, wherever it appeared).
+    Trailer,
+    Done,
+}
+
+/// Drives a [`serde_json`]-flavored byte stream one JSON value at a time,
+/// yielding each `Feature` of a FeatureCollection's `features` array as soon
+/// as it's parsed rather than collecting the whole array into memory first.
+///
+/// This sidesteps the self-referential-borrow problem of trying to hand a
+/// `serde::de::SeqAccess` out of a `Visitor::visit_map` call: instead of
+/// asking `serde_json` to drive the top-level object, we scan its bytes
+/// ourselves, only handing individual already-extracted `Feature` values to
+/// the selected [`JsonBackend`] `B` (`serde_json` by default) for the
+/// (non-streaming, single-value) conversion into `D`.
+pub struct FeatureCollectionReader<R, D, B = SerdeJsonBackend> {
+    cursor: ByteCursor<R>,
+    state: ScanState,
+    saw_type: bool,
+    saw_features: bool,
+    strict: bool,
+    foreign_members: HashMap<String, Box<RawValue>>,
+    _marker: PhantomData<(D, B)>,
+}
+
+impl<R: Read, D, B> FeatureCollectionReader<R, D, B> {
+    fn new(reader: R, strict: bool) -> Result<Self> {
+        let mut cursor = ByteCursor::new(reader);
+        cursor.skip_whitespace()?;
+        cursor.expect_byte(b'{')?;
+        Ok(Self {
+            cursor,
+            state: ScanState::Header,
+            saw_type: false,
+            saw_features: false,
+            strict,
+            foreign_members: HashMap::new(),
+            _marker: PhantomData,
+        })
+    }
+
+    /// Foreign members found on the `FeatureCollection` object itself (e.g.
+    /// `bbox`, a CRS hint, or a vendor extension), preserved byte-for-byte as
+    /// [`RawValue`]s. Populated as they're encountered: members before
+    /// `features` are available once the first call to `next` has returned,
+    /// but any that follow `features` only appear once the iterator has been
+    /// fully drained.
+    ///
+    /// Always empty when this reader was built with
+    /// [`FeatureReader::strict`](crate::FeatureReader::strict), since in
+    /// that mode a foreign member is a hard error instead.
+    pub fn foreign_members(&self) -> &HashMap<String, Box<RawValue>> {
+        &self.foreign_members
+    }
+
+    /// Scans object members until either the `features` array is found
+    /// (returning `Ok(true)`, cursor positioned just inside `[`) or the
+    /// object ends having already consumed it (returning `Ok(false)`).
+    ///
+    /// Used for both the initial scan (before `features`) and the trailer
+    /// scan (any members, including a `type` member, that follow it).
+    fn advance_to_features_or_end(&mut self) -> Result<bool> {
+        loop {
+            self.cursor.skip_whitespace()?;
+            match self.cursor.peek_byte()? {
+                Some(b'}') => {
+                    self.cursor.read_byte()?;
+                    return Ok(false);
+                }
+                Some(b',') => {
+                    self.cursor.read_byte()?;
+                    continue;
+                }
+                Some(b'"') => {
+                    let key = self.cursor.read_json_string()?;
+                    self.cursor.skip_whitespace()?;
+                    self.cursor.expect_byte(b':')?;
+                    self.cursor.skip_whitespace()?;
+
+                    if key == "type" {
+                        let value = self.cursor.read_json_string()?;
+                        if value != "FeatureCollection" {
+                            return Err(scan_error("invalid type for feature collection"));
+                        }
+                        self.saw_type = true;
+                    } else if key == "features" {
+                        if self.saw_features {
+                            return Err(scan_error(
+                                "Encountered more than one list of `features`",
+                            ));
+                        }
+                        self.saw_features = true;
+                        self.cursor.skip_whitespace()?;
+                        self.cursor.expect_byte(b'[')?;
+                        return Ok(true);
+                    } else if self.strict {
+                        return Err(scan_error(
+                            "foreign members are not handled by FeatureCollection deserializer",
+                        ));
+                    } else {
+                        let mut buf = Vec::new();
+                        self.cursor.consume_value(&mut buf)?;
+                        let raw = serde_json::from_slice::<Box<RawValue>>(&buf)?;
+                        self.foreign_members.insert(key, raw);
+                    }
+                }
+                Some(other) => {
+                    return Err(scan_error(format!(
+                        "unexpected byte '{}' in FeatureCollection",
+                        other as char
+                    )))
+                }
+                None => return Err(scan_error("unexpected end of input in FeatureCollection")),
+            }
+        }
+    }
+
+    /// Reads the raw bytes of the next element of the `features` array, if
+    /// any, leaving their interpretation to a [`JsonBackend`].
+    fn next_feature_bytes(&mut self) -> Result<Option<Vec<u8>>> {
+        self.cursor.skip_whitespace()?;
+        match self.cursor.peek_byte()? {
+            Some(b']') => {
+                self.cursor.read_byte()?;
+                Ok(None)
+            }
+            Some(b',') => {
+                self.cursor.read_byte()?;
+                self.cursor.skip_whitespace()?;
+                let mut buf = Vec::new();
+                self.cursor.consume_value(&mut buf)?;
+                Ok(Some(buf))
+            }
+            Some(_) => {
+                let mut buf = Vec::new();
+                self.cursor.consume_value(&mut buf)?;
+                Ok(Some(buf))
+            }
+            None => Err(scan_error("unexpected end of input in `features` array")),
+        }
+    }
+}
+
+impl<R: Read, D: DeserializeOwned, B: JsonBackend> Iterator for FeatureCollectionReader<R, D, B> {
+    type Item = Result<D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.state {
+                ScanState::Header => match self.advance_to_features_or_end() {
+                    Ok(true) => self.state = ScanState::Features,
+                    Ok(false) => {
+                        self.state = ScanState::Done;
+                        return Some(Err(scan_error("No `features` field was found")));
+                    }
+                    Err(err) => {
+                        self.state = ScanState::Done;
+                        return Some(Err(err));
+                    }
+                },
+                ScanState::Features => match self.next_feature_bytes() {
+                    Ok(Some(mut bytes)) => {
+                        return Some(B::deserialize_feature::<D>(&mut bytes, self.strict));
+                    }
+                    Ok(None) => self.state = ScanState::Trailer,
+                    Err(err) => {
+                        self.state = ScanState::Done;
+                        return Some(Err(err));
+                    }
+                },
+                ScanState::Trailer => {
+                    self.state = ScanState::Done;
+                    return match self.advance_to_features_or_end() {
+                        Ok(_) if self.saw_type => None,
+                        Ok(_) => Some(Err(scan_error("No `type` field was found"))),
+                        Err(err) => Some(Err(err)),
+                    };
+                }
+                ScanState::Done => return None,
+            }
+        }
+    }
+}
+
+pub(crate) fn deserialize_collection_features_from_reader<R: Read, D, B: JsonBackend>(
+    feature_collection_reader: R,
+    strict: bool,
+) -> Result<FeatureCollectionReader<R, D, B>>
+where
+    D: DeserializeOwned,
+{
+    FeatureCollectionReader::new(feature_collection_reader, strict)
 }
 
 pub fn deserialize_geometry<'de, D, G>(deserializer: D) -> std::result::Result<G, D::Error>
@@ -181,6 +580,21 @@ where
     })
 }
 
+/// Like [`deserialize_geometry`], but for a field whose value is a
+/// WKT-encoded string (e.g. `"POINT(125.6 10.1)"`) rather than a GeoJSON
+/// geometry object -- a common shape for data exported from PostGIS.
+#[cfg(feature = "wkt")]
+pub fn deserialize_geometry_from_wkt<'de, D, G>(deserializer: D) -> std::result::Result<G, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    G: TryFromWkt<f64>,
+    G::Error: std::fmt::Display,
+{
+    let wkt_str = String::deserialize(deserializer)?;
+    G::try_from_wkt_str(&wkt_str)
+        .map_err(|err| D::Error::custom(format!("unable to parse WKT geometry: {}", err)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -237,7 +651,11 @@ mod tests {
             let feature_collection_string = feature_collection_string();
             let bytes_reader = feature_collection_string.as_bytes();
 
-            let records: Vec<MyStruct> = deserialize_collection_features_from_reader(bytes_reader)
+            let records: Vec<MyStruct> =
+                deserialize_collection_features_from_reader::<_, MyStruct, SerdeJsonBackend>(
+                    bytes_reader,
+                    true,
+                )
                 .expect("a valid feature collection")
                 .map(|result| result.expect("a valid feature"))
                 .collect();
@@ -258,5 +676,199 @@ mod tests {
             assert_eq!(records[1].name, "Neverland");
             assert_eq!(records[1].age, 456);
         }
+
+        #[test]
+        fn feature_collection_with_type_after_features() {
+            let feature_collection_string = json!({
+                "features": [
+                    {
+                      "type": "Feature",
+                      "geometry": {
+                        "type": "Point",
+                        "coordinates": [125.6, 10.1]
+                      },
+                      "properties": {
+                        "name": "Dinagat Islands",
+                        "age": 123
+                      }
+                    }
+                ],
+                "type": "FeatureCollection"
+            })
+            .to_string();
+            let bytes_reader = feature_collection_string.as_bytes();
+
+            let records: Vec<MyStruct> =
+                deserialize_collection_features_from_reader::<_, MyStruct, SerdeJsonBackend>(
+                    bytes_reader,
+                    true,
+                )
+                .expect("a valid feature collection")
+                .map(|result| result.expect("a valid feature"))
+                .collect();
+
+            assert_eq!(records.len(), 1);
+            assert_eq!(records[0].name, "Dinagat Islands");
+        }
+
+        #[test]
+        fn foreign_members_are_captured_unless_strict() {
+            // Written as a raw string (rather than `json!()`, whose `Value` is
+            // alphabetically ordered without the `preserve_order` feature) so
+            // that `crs` is deliberately placed after `features`, exercising
+            // the `ScanState::Trailer` path where a foreign member is only
+            // discovered once the `features` array has already been drained.
+            let feature_collection_string = r#"{
+                "type": "FeatureCollection",
+                "bbox": [-10.0, -10.0, 10.0, 10.0],
+                "features": [
+                    {
+                      "type": "Feature",
+                      "geometry": {
+                        "type": "Point",
+                        "coordinates": [125.6, 10.1]
+                      },
+                      "properties": {
+                        "name": "Dinagat Islands",
+                        "age": 123
+                      }
+                    }
+                ],
+                "crs": {"type": "name", "properties": {"name": "urn:ogc:def:crs:OGC:1.3:CRS84"}}
+            }"#;
+
+            let bytes_reader = feature_collection_string.as_bytes();
+            let mut reader: FeatureCollectionReader<_, MyStruct> =
+                deserialize_collection_features_from_reader::<_, MyStruct, SerdeJsonBackend>(
+                    bytes_reader,
+                    false,
+                )
+                .expect("a valid feature collection");
+            let records: Vec<MyStruct> = (&mut reader)
+                .map(|result| result.expect("a valid feature"))
+                .collect();
+
+            assert_eq!(records.len(), 1);
+            let foreign_members = reader.foreign_members();
+            assert!(foreign_members.contains_key("bbox"));
+            assert!(foreign_members.contains_key("crs"));
+        }
+
+        #[test]
+        fn foreign_members_error_when_strict() {
+            let feature_collection_string = json!({
+                "type": "FeatureCollection",
+                "bbox": [-10.0, -10.0, 10.0, 10.0],
+                "features": []
+            })
+            .to_string();
+
+            let bytes_reader = feature_collection_string.as_bytes();
+            let mut reader: FeatureCollectionReader<_, MyStruct> =
+                deserialize_collection_features_from_reader::<_, MyStruct, SerdeJsonBackend>(
+                    bytes_reader,
+                    true,
+                )
+                .expect("a valid feature collection");
+
+            assert!(reader.next().expect("an error, not end of stream").is_err());
+        }
+    }
+
+    // `MyWktStruct` below uses `geo_types::Geometry<f64>`, so this module also
+    // needs `geo-types` enabled; `wkt` alone isn't enough to compile it.
+    #[cfg(all(feature = "wkt", feature = "geo-types"))]
+    mod wkt_tests {
+        use super::*;
+
+        #[derive(Deserialize)]
+        struct MyWktStruct {
+            #[serde(deserialize_with = "deserialize_geometry_from_wkt")]
+            geometry: geo_types::Geometry<f64>,
+            name: String,
+        }
+
+        #[test]
+        fn feature_with_wkt_geometry_member() {
+            // A WKT-encoded geometry (as exported from e.g. PostGIS) in the
+            // Feature's actual `geometry` member, not a GeoJSON geometry object.
+            let feature_collection_string = json!({
+                "type": "FeatureCollection",
+                "features": [
+                    {
+                      "type": "Feature",
+                      "geometry": "POINT(125.6 10.1)",
+                      "properties": {
+                        "name": "Dinagat Islands"
+                      }
+                    }
+                ]
+            })
+            .to_string();
+            let bytes_reader = feature_collection_string.as_bytes();
+
+            let records: Vec<MyWktStruct> =
+                deserialize_collection_features_from_reader::<_, MyWktStruct, SerdeJsonBackend>(
+                    bytes_reader,
+                    true,
+                )
+                .expect("a valid feature collection")
+                .map(|result| result.expect("a valid feature"))
+                .collect();
+
+            assert_eq!(records.len(), 1);
+            assert_eq!(
+                records[0].geometry,
+                geo_types::point!(x: 125.6, y: 10.1).into()
+            );
+            assert_eq!(records[0].name, "Dinagat Islands");
+        }
+    }
+
+    #[cfg(feature = "simd-json")]
+    mod simd_json_tests {
+        use super::*;
+
+        #[derive(Deserialize)]
+        struct MyStruct {
+            geometry: crate::Geometry,
+            name: String,
+            age: u64,
+        }
+
+        #[test]
+        fn feature_collection_via_simd_json_backend() {
+            let feature_collection_string = json!({
+                "type": "FeatureCollection",
+                "features": [
+                    {
+                      "type": "Feature",
+                      "geometry": {
+                        "type": "Point",
+                        "coordinates": [125.6, 10.1]
+                      },
+                      "properties": {
+                        "name": "Dinagat Islands",
+                        "age": 123
+                      }
+                    }
+                ]
+            })
+            .to_string();
+            let bytes_reader = feature_collection_string.as_bytes();
+
+            let records: Vec<MyStruct> =
+                deserialize_collection_features_from_reader::<_, MyStruct, SimdJsonBackend>(
+                    bytes_reader,
+                    true,
+                )
+                .expect("a valid feature collection")
+                .map(|result| result.expect("a valid feature"))
+                .collect();
+
+            assert_eq!(records.len(), 1);
+            assert_eq!(records[0].name, "Dinagat Islands");
+            assert_eq!(records[0].age, 123);
+        }
     }
 }
@@ -1,4 +1,7 @@
-use crate::deserialize::deserialize_collection_features_from_reader;
+use crate::deserialize::{
+    deserialize_collection_features_from_reader, FeatureCollectionReader, JsonBackend,
+    SerdeJsonBackend,
+};
 use crate::{Feature, Result};
 
 use serde::de::DeserializeOwned;
@@ -7,11 +10,42 @@ use std::io::Read;
 
 pub struct FeatureReader<R> {
     reader: R,
+    strict: bool,
 }
 
 impl<'r, R: Read> FeatureReader<R> {
     pub fn from_reader(reader: R) -> Self {
-        Self { reader }
+        Self {
+            reader,
+            strict: false,
+        }
+    }
+
+    /// Reject GeoJSON foreign members (any `FeatureCollection` or `Feature`
+    /// member besides `type`/`features`/`geometry`/`properties`) with an
+    /// error instead of preserving them.
+    ///
+    /// By default, foreign members are kept: collection-level ones (e.g.
+    /// `bbox`, a CRS hint, a vendor extension) are exposed via
+    /// [`FeatureCollectionReader::foreign_members`], and feature-level ones
+    /// are flattened onto the `deserialize`d target alongside `properties`.
+    ///
+    /// The flag applies to [`deserialize`](Self::deserialize) and
+    /// [`deserialize_with_backend`](Self::deserialize_with_backend);
+    /// [`features`](Self::features) does not consult it.
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    /// Deserialize the features of a FeatureCollection using a specific
+    /// [`JsonBackend`] rather than the default `serde_json`-based one, e.g.
+    /// to swap in a SIMD-accelerated parser for each `Feature`'s fields. See
+    /// [`JsonBackend`] for what this does (and doesn't) speed up.
+    pub fn deserialize_with_backend<D: DeserializeOwned, B: JsonBackend>(
+        self,
+    ) -> Result<FeatureCollectionReader<R, D, B>> {
+        deserialize_collection_features_from_reader(self.reader, self.strict)
     }
 
     /// Iterate over the individual [`Feature`s](Feature) of a FeatureCollection
@@ -190,8 +224,22 @@ impl<'r, R: Read> FeatureReader<R> {
     ///     }
     /// }
     /// ```
-    pub fn deserialize<D: DeserializeOwned>(self) -> Result<impl Iterator<Item = Result<D>>> {
-        Ok(deserialize_collection_features_from_reader(self.reader)?)
+    pub fn deserialize<D: DeserializeOwned>(
+        self,
+    ) -> Result<FeatureCollectionReader<R, D, SerdeJsonBackend>> {
+        deserialize_collection_features_from_reader(self.reader, self.strict)
+    }
+}
+
+impl<'r> FeatureReader<&'r [u8]> {
+    /// Build a reader directly over an in-memory buffer.
+    ///
+    /// This takes `&mut [u8]` (rather than `&[u8]`) so that it lines up with
+    /// in-place backends like `simd-json`, which parse the buffer without
+    /// copying it; it's read-only today regardless of which
+    /// [`JsonBackend`](crate::deserialize::JsonBackend) ends up being used.
+    pub fn from_bytes(bytes: &'r mut [u8]) -> Self {
+        Self::from_reader(&*bytes)
     }
 }
 
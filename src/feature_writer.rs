@@ -0,0 +1,164 @@
+use crate::{Error, Result};
+
+use serde::de::Error as DeError;
+use serde::Serialize;
+
+use std::io::Write;
+
+fn write_error<T: std::fmt::Display>(msg: T) -> Error {
+    <serde_json::Error as DeError>::custom(msg).into()
+}
+
+/// Streams a FeatureCollection out one [`Feature`](crate::Feature) at a time,
+/// writing each as soon as it's given rather than building the whole
+/// collection in memory first -- the serialization counterpart to
+/// [`FeatureReader`](crate::FeatureReader).
+///
+/// Each value passed to [`FeatureWriter::serialize_feature`] is serialized to
+/// a JSON object; a `geometry` field (typically produced with
+/// `#[serde(serialize_with = "serialize_geometry")]`) becomes the Feature's
+/// `geometry` member, and the remaining fields are nested under `properties`,
+/// mirroring how [`FeatureReader::deserialize`](crate::FeatureReader::deserialize)
+/// flattens `properties` back onto the target struct.
+///
+/// # Examples
+///
+/// ```
+/// use geojson::FeatureWriter;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct MyStruct {
+///     geometry: geojson::Geometry,
+///     name: String,
+///     age: u64,
+/// }
+///
+/// let mut buffer = Vec::new();
+/// let mut writer = FeatureWriter::from_writer(&mut buffer).unwrap();
+/// writer
+///     .serialize_feature(&MyStruct {
+///         geometry: geojson::Geometry::new(geojson::Value::Point(vec![125.6, 10.1])),
+///         name: "Dinagat Islands".to_string(),
+///         age: 123,
+///     })
+///     .unwrap();
+/// writer.finish().unwrap();
+///
+/// let expected = serde_json::json!({
+///     "type": "FeatureCollection",
+///     "features": [
+///         {
+///             "type": "Feature",
+///             "geometry": {"type": "Point", "coordinates": [125.6, 10.1]},
+///             "properties": {"name": "Dinagat Islands", "age": 123}
+///         }
+///     ]
+/// });
+/// let actual: serde_json::Value = serde_json::from_slice(&buffer).unwrap();
+/// assert_eq!(actual, expected);
+/// ```
+pub struct FeatureWriter<W> {
+    writer: W,
+    wrote_a_feature: bool,
+}
+
+impl<W: Write> FeatureWriter<W> {
+    /// Start streaming a FeatureCollection, writing its opening
+    /// `{"type":"FeatureCollection","features":[` preamble immediately.
+    pub fn from_writer(mut writer: W) -> Result<Self> {
+        writer
+            .write_all(br#"{"type":"FeatureCollection","features":["#)
+            .map_err(write_error)?;
+        Ok(Self {
+            writer,
+            wrote_a_feature: false,
+        })
+    }
+
+    /// Serialize one value as a `Feature` and write it immediately.
+    ///
+    /// `value` must serialize to a JSON object; any field named `geometry`
+    /// becomes the Feature's `geometry` member and every other field is
+    /// nested under `properties`.
+    pub fn serialize_feature<D: Serialize>(&mut self, value: &D) -> Result<()> {
+        // Build and validate the whole Feature value before writing anything,
+        // so a bad `value` (fails to serialize, or isn't an object) can't
+        // leave a dangling separator with no feature to follow it.
+        let mut fields = match serde_json::to_value(value)? {
+            serde_json::Value::Object(fields) => fields,
+            _ => return Err(write_error("a Feature must serialize to a JSON object")),
+        };
+        let geometry = fields.remove("geometry");
+        let feature = serde_json::json!({
+            "type": "Feature",
+            "geometry": geometry,
+            "properties": fields,
+        });
+
+        if self.wrote_a_feature {
+            self.writer.write_all(b",").map_err(write_error)?;
+        }
+        self.wrote_a_feature = true;
+
+        serde_json::to_writer(&mut self.writer, &feature)?;
+        Ok(())
+    }
+
+    /// Write the closing `]}` and return the underlying writer.
+    pub fn finish(mut self) -> Result<W> {
+        self.writer.write_all(b"]}").map_err(write_error)?;
+        Ok(self.writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct MyStruct {
+        geometry: crate::Geometry,
+        name: String,
+    }
+
+    #[derive(Serialize)]
+    struct NotAnObject(u32);
+
+    #[test]
+    fn finish_with_no_features_is_valid_json() {
+        let mut buffer = Vec::new();
+        let writer = FeatureWriter::from_writer(&mut buffer).expect("a valid writer");
+        writer.finish().expect("closing an empty stream still succeeds");
+
+        let value: serde_json::Value =
+            serde_json::from_slice(&buffer).expect("still valid JSON");
+        assert_eq!(value["type"], "FeatureCollection");
+        assert_eq!(value["features"].as_array().expect("an array").len(), 0);
+    }
+
+    #[test]
+    fn invalid_feature_mid_stream_does_not_corrupt_the_stream() {
+        let mut buffer = Vec::new();
+        let mut writer = FeatureWriter::from_writer(&mut buffer).expect("a valid writer");
+
+        writer
+            .serialize_feature(&MyStruct {
+                geometry: crate::Geometry::new(crate::Value::Point(vec![125.6, 10.1])),
+                name: "Dinagat Islands".to_string(),
+            })
+            .expect("a valid feature");
+
+        // `NotAnObject` serializes to a bare JSON number, not an object, so
+        // this must fail without writing a dangling `,` into the stream.
+        assert!(writer.serialize_feature(&NotAnObject(42)).is_err());
+
+        writer.finish().expect("closing the stream still succeeds");
+
+        let value: serde_json::Value =
+            serde_json::from_slice(&buffer).expect("still valid JSON");
+        let features = value["features"].as_array().expect("a features array");
+        assert_eq!(features.len(), 1);
+        assert_eq!(features[0]["properties"]["name"], "Dinagat Islands");
+    }
+}
@@ -0,0 +1,49 @@
+use serde::Serialize;
+
+/// Like [`deserialize_geometry`](crate::deserialize::deserialize_geometry), but
+/// for serializing: usable as `#[serde(serialize_with = "serialize_geometry")]`
+/// on a field holding any geometry type convertible into this crate's
+/// [`Geometry`](crate::Geometry), e.g. `geo_types::Geometry<f64>`.
+pub fn serialize_geometry<S, G>(
+    geometry: &G,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+    for<'a> &'a G: Into<crate::Geometry>,
+{
+    let geojson_geometry: crate::Geometry = geometry.into();
+    geojson_geometry.serialize(serializer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "geo-types")]
+    mod geo_types_tests {
+        use super::*;
+
+        #[derive(Serialize)]
+        struct MyStruct {
+            #[serde(serialize_with = "serialize_geometry")]
+            geometry: geo_types::Geometry<f64>,
+            name: String,
+        }
+
+        #[test]
+        fn serializes_as_geojson_geometry() {
+            let my_struct = MyStruct {
+                geometry: geo_types::point!(x: 125.6, y: 10.1).into(),
+                name: "Dinagat Islands".to_string(),
+            };
+
+            let value = serde_json::to_value(&my_struct).expect("serializable");
+            assert_eq!(
+                value["geometry"],
+                serde_json::json!({"type": "Point", "coordinates": [125.6, 10.1]})
+            );
+            assert_eq!(value["name"], "Dinagat Islands");
+        }
+    }
+}